@@ -13,98 +13,164 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+use crate::led_pwm::Pin;
 use log::error;
 use std::error::Error;
 use std::sync::Arc;
-#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-use tokio::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::mpsc;
 
-#[derive(Default)]
-pub struct LedManager {
-    #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-    is_running: Mutex<bool>,
+// The baseline drove both the blinker and the lock light off the same
+// physical pin; keep that wiring here rather than inventing a second pin
+// that may not exist on the target board. Since it's one physical pin,
+// it needs exactly one persistent owner below (`rppal` refuses to open
+// the same GPIO twice, and two owners would fight over its duty cycle
+// even if it did).
+const GPIO: u8 = 26;
+
+const STEP: Duration = Duration::from_millis(30);
+const FADE_DURATION: Duration = Duration::from_millis(3000);
+const SHUTDOWN_FADE_DURATION: Duration = Duration::from_millis(500);
+
+/// One leg of an animation: interpolate towards `target_duty` over
+/// `duration`, starting from wherever the channel currently is.
+#[derive(Debug, Clone, Copy)]
+pub struct Keyframe {
+    pub target_duty: f32,
+    pub duration: Duration,
 }
 
-impl LedManager {
-    // Method to lock the light
-    pub fn lock_light(self: &Arc<Self>) -> Result<(), Box<dyn Error>> {
-        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-        {
-            let manager = Arc::clone(self);
-            tokio::spawn(async move {
-                let mut is_running = manager.is_running.lock().await;
-                if *is_running {
-                    error!("Lock light task is already running");
+/// A single named PWM channel driven by a persistent background task. New
+/// keyframes sent via `retarget` preempt whatever animation is in flight:
+/// the task picks them up between interpolation steps and continues from
+/// the current duty cycle instead of finishing the old animation first.
+struct Channel {
+    retarget: mpsc::UnboundedSender<Vec<Keyframe>>,
+}
+
+impl Channel {
+    fn spawn(name: &'static str, gpio: u8) -> Self {
+        let (retarget_tx, mut retarget_rx) = mpsc::unbounded_channel::<Vec<Keyframe>>();
+
+        tokio::spawn(async move {
+            let mut pin = match Pin::open(gpio) {
+                Ok(pin) => pin,
+                Err(e) => {
+                    error!("Failed to open GPIO {} for {} channel: {:?}", gpio, name, e);
                     return;
                 }
-                *is_running = true; // Mark task as running
+            };
 
-                if let Err(e) = led_pwm::lock_light().await {
-                    error!("Error locking light: {:?}", e);
-                }
+            let mut duty = 0.0f32;
+            let mut keyframes: Vec<Keyframe> = Vec::new();
 
-                *is_running = false; // Mark task as finished
-            });
-            return Ok(());
-        }
-        #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
-        {
-            return Ok(());
-        }
-    }
+            loop {
+                let Some(keyframe) = keyframes.first().copied() else {
+                    match retarget_rx.recv().await {
+                        Some(new_keyframes) => keyframes = new_keyframes,
+                        None => return,
+                    }
+                    continue;
+                };
 
-    // Method to unlock the light
-    pub fn unlock_light(self: &Arc<Self>) -> Result<(), Box<dyn Error>> {
-        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-        {
-            let manager = Arc::clone(self);
-            tokio::spawn(async move {
-                let mut is_running = manager.is_running.lock().await;
-                if *is_running {
-                    error!("Unlock light task is already running");
-                    return;
+                let steps = (keyframe.duration.as_millis() / STEP.as_millis()).max(1) as u32;
+                let step_size = (keyframe.target_duty - duty) / steps as f32;
+                let mut preempted = false;
+
+                for _ in 0..steps {
+                    tokio::select! {
+                        biased;
+                        new_keyframes = retarget_rx.recv() => {
+                            match new_keyframes {
+                                Some(new_keyframes) => keyframes = new_keyframes,
+                                None => return,
+                            }
+                            preempted = true;
+                        }
+                        _ = tokio::time::sleep(STEP) => {
+                            duty += step_size;
+                            if let Err(e) = pin.set_duty(duty) {
+                                error!("Failed to set duty cycle on {} channel: {:?}", name, e);
+                            }
+                        }
+                    }
+                    if preempted {
+                        break;
+                    }
                 }
-                *is_running = true; // Mark task as running
 
-                if let Err(e) = led_pwm::unlock_light().await {
-                    error!("Error unlocking light: {:?}", e);
+                if !preempted {
+                    duty = keyframe.target_duty;
+                    if let Err(e) = pin.set_duty(duty) {
+                        error!("Failed to set duty cycle on {} channel: {:?}", name, e);
+                    }
+                    keyframes.remove(0);
                 }
+            }
+        });
 
-                *is_running = false; // Mark task as finished
-            });
-            return Ok(());
-        }
-        #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
-        {
-            return Ok(());
+        Self {
+            retarget: retarget_tx,
         }
     }
 
-    // Method for blinker LED
-    pub fn blinker_led(self: &Arc<Self>, _state: bool) -> Result<(), Box<dyn Error>> {
-        #[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
-        {
-            let manager = Arc::clone(self);
-            tokio::spawn(async move {
-                let mut is_running = manager.is_running.lock().await;
-                if *is_running {
-                    error!("Blinker LED task is already running");
-                    return;
-                }
-                *is_running = true; // Mark task as running
+    fn retarget(&self, keyframes: Vec<Keyframe>) -> Result<(), Box<dyn Error>> {
+        self.retarget
+            .send(keyframes)
+            .map_err(|e| -> Box<dyn Error> { Box::new(e) })
+    }
+}
 
-                if let Err(e) = led_pwm::blinker_led(_state).await {
-                    error!("Error in blinker_led: {:?}", e);
-                }
+pub struct LedManager {
+    led: Channel,
+}
 
-                *is_running = false; // Mark task as finished
-            });
-            return Ok(());
-        }
-        #[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
-        {
-            return Ok(());
+impl Default for LedManager {
+    fn default() -> Self {
+        Self {
+            led: Channel::spawn("led", GPIO),
         }
     }
 }
+
+impl LedManager {
+    // Method to lock the light
+    pub fn lock_light(self: &Arc<Self>) -> Result<(), Box<dyn Error>> {
+        self.led.retarget(vec![Keyframe {
+            target_duty: 0.0,
+            duration: FADE_DURATION,
+        }])
+    }
+
+    // Method to unlock the light
+    pub fn unlock_light(self: &Arc<Self>) -> Result<(), Box<dyn Error>> {
+        self.led.retarget(vec![Keyframe {
+            target_duty: 1.0,
+            duration: FADE_DURATION,
+        }])
+    }
+
+    // Method for blinker LED
+    pub fn blinker_led(self: &Arc<Self>, state: bool) -> Result<(), Box<dyn Error>> {
+        self.led.retarget(vec![Keyframe {
+            target_duty: if state { 1.0 } else { 0.0 },
+            duration: Duration::ZERO,
+        }])
+    }
+
+    /// Drives the LED to a defined off state. Used on shutdown so the pin
+    /// isn't left latched at an arbitrary duty cycle if a fade or blink was
+    /// mid-flight; the retarget preempts it rather than waiting for it to
+    /// finish. This is a real guarantee only because `LedManager` now has a
+    /// single `Channel` owning the pin — with two independent channels on
+    /// the same GPIO, the one that lost the open race had a dead receiver
+    /// and its retarget here would have silently done nothing.
+    pub async fn shutdown(self: &Arc<Self>) -> Result<(), Box<dyn Error>> {
+        self.led.retarget(vec![Keyframe {
+            target_duty: 0.0,
+            duration: SHUTDOWN_FADE_DURATION,
+        }])?;
+        tokio::time::sleep(SHUTDOWN_FADE_DURATION).await;
+        Ok(())
+    }
+}
@@ -13,77 +13,48 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use rppal::gpio::Gpio;
 use std::error::Error;
 use std::time::Duration;
-use tokio::time::sleep;
 
-const GPIO_PWM: u8 = 26;
-const PERIOD_US: u64 = 10000; // Period: 10 ms (100 Hz).
-const PULSE_MIN_US: u64 = 0;
+pub const PERIOD_US: u64 = 10000; // Period: 10 ms (100 Hz).
+#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
 const PULSE_MAX_US: u64 = PERIOD_US - 1000;
 
-fn led_on(
-    pin: &mut rppal::gpio::OutputPin,
-    period: u64,
-    pulse_width: u64,
-) -> Result<(), Box<dyn Error>> {
-    pin.set_pwm(
-        Duration::from_micros(period),
-        Duration::from_micros(pulse_width),
-    )?;
-    Ok(())
-}
-
-fn led_off(pin: &mut rppal::gpio::OutputPin, period: u64) -> Result<(), Box<dyn Error>> {
-    pin.set_pwm(Duration::from_micros(period), Duration::from_micros(0))?;
-    Ok(())
-}
+/// A single PWM-capable GPIO pin. Holds the pin open for the lifetime of
+/// the animation engine driving it instead of re-opening it on every call.
+#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+pub struct Pin(rppal::gpio::OutputPin);
 
-pub async fn blinker_led(state: bool) -> Result<(), Box<dyn Error>> {
-    let mut pin = Gpio::new()?.get(GPIO_PWM)?.into_output();
-    if state {
-        led_on(&mut pin, PERIOD_US, PULSE_MAX_US)?;
-    } else {
-        led_off(&mut pin, PERIOD_US)?;
+#[cfg(any(target_arch = "arm", target_arch = "aarch64"))]
+impl Pin {
+    pub fn open(gpio: u8) -> Result<Self, Box<dyn Error>> {
+        Ok(Self(rppal::gpio::Gpio::new()?.get(gpio)?.into_output()))
     }
-    Ok(())
-}
 
-async fn fade_in(
-    pin: &mut rppal::gpio::OutputPin,
-    period: u64,
-    pulse_width_min: u64,
-    pulse_width_max: u64,
-) -> Result<(), Box<dyn Error>> {
-    for pulse in (pulse_width_min..=pulse_width_max).step_by(100) {
-        pin.set_pwm(Duration::from_micros(period), Duration::from_micros(pulse))?;
-        sleep(Duration::from_millis(30)).await;
+    /// Sets the duty cycle, clamped to `0.0..=1.0`.
+    pub fn set_duty(&mut self, duty: f32) -> Result<(), Box<dyn Error>> {
+        let pulse_width = (duty.clamp(0.0, 1.0) * PULSE_MAX_US as f32) as u64;
+        self.0.set_pwm(
+            Duration::from_micros(PERIOD_US),
+            Duration::from_micros(pulse_width),
+        )?;
+        Ok(())
     }
-    Ok(())
 }
 
-async fn fade_out(
-    pin: &mut rppal::gpio::OutputPin,
-    period: u64,
-    pulse_width_min: u64,
-    pulse_width_max: u64,
-) -> Result<(), Box<dyn Error>> {
-    for pulse in (pulse_width_min..=pulse_width_max).rev().step_by(100) {
-        pin.set_pwm(Duration::from_micros(period), Duration::from_micros(pulse))?;
-        sleep(Duration::from_millis(30)).await;
-    }
-    Ok(())
-}
+// Host-side stub: there is no GPIO to drive, but it still accepts every
+// call so the interpolation loop above it runs (and can be exercised)
+// exactly the same way off-target.
+#[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
+pub struct Pin;
 
-pub async fn lock_light() -> Result<(), Box<dyn Error>> {
-    let mut pin = Gpio::new()?.get(GPIO_PWM)?.into_output();
-    fade_out(&mut pin, PERIOD_US, PULSE_MIN_US, PULSE_MAX_US).await?;
-    Ok(())
-}
+#[cfg(not(any(target_arch = "arm", target_arch = "aarch64")))]
+impl Pin {
+    pub fn open(_gpio: u8) -> Result<Self, Box<dyn Error>> {
+        Ok(Self)
+    }
 
-pub async fn unlock_light() -> Result<(), Box<dyn Error>> {
-    let mut pin = Gpio::new()?.get(GPIO_PWM)?.into_output();
-    fade_in(&mut pin, PERIOD_US, PULSE_MIN_US, PULSE_MAX_US).await?;
-    Ok(())
+    pub fn set_duty(&mut self, _duty: f32) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
 }
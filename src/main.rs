@@ -15,33 +15,38 @@
 
 use chrono::Local;
 use clap::Parser;
-use log::{error, trace};
+use futures::stream::{self, Stream, StreamExt};
+use log::{error, info, trace};
+use prost::Message;
 use slint::*;
 use std::fmt::format as fmt_format;
+use std::pin::Pin;
 use std::sync::{Arc, RwLock};
-use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use vehicle_dashboard::events::battery::BatteryData;
 use vehicle_dashboard::events::exterior::Exterior;
 use vehicle_dashboard::events::speed::Speed;
 use vehicle_dashboard::events::state::{LockState, State};
 use vehicle_dashboard::events::trip_data::TripData;
 use vehicle_dashboard::led_manager::LedManager;
-use vehicle_dashboard::subscribers::SubscriberTaskSpawner;
+use vehicle_dashboard::subscribers::ZenohSubscriber;
 use vehicle_dashboard::topics::*;
-use zenoh::Config;
+use zenoh::{Config, Session};
+
+mod timer_wheel;
+use timer_wheel::AnimationScheduler;
 
 #[derive(clap::Parser, Clone, PartialEq, Eq, Hash, Debug)]
 struct Args {}
 
 slint::include_modules!();
 
-// Define the Model struct to hold the latest data
+// Holds only the state needed for cross-field logic that the tell-tale
+// timer can't get from a single adapter: the lock state gates the
+// blinker LED. Everything else is pushed straight into its adapter as
+// soon as a sample arrives, so it no longer lives here.
 struct Model {
-    battery_data: Option<BatteryData>,
     lock_state: Option<LockState>,
-    exterior: Option<Exterior>,
-    speed: Option<Speed>,
-    trip_data: Option<TripData>,
 }
 
 fn minutes_to_ddhhmm(minutes: f32) -> String {
@@ -53,9 +58,14 @@ fn minutes_to_ddhhmm(minutes: f32) -> String {
     fmt_format(format_args!("{:02}:{:02}:{:02}", days, hours, mins))
 }
 
-fn setup(window: &MainWindow, model: Arc<RwLock<Model>>, led_manager: Arc<LedManager>) -> Timer {
-    let update_timer = Timer::default();
+const TICK: std::time::Duration = std::time::Duration::from_millis(300);
 
+fn register_animations(
+    scheduler: &mut AnimationScheduler,
+    window: &MainWindow,
+    model: Arc<RwLock<Model>>,
+    led_manager: Arc<LedManager>,
+) {
     let messages = [
         "Heads up! Your package is more fragile than your last relationship. Handle with care!",
         "Destination in sight. Just 12 more turns, 3 speed bumps, and 1 curious squirrel",
@@ -79,111 +89,31 @@ fn setup(window: &MainWindow, model: Arc<RwLock<Model>>, led_manager: Arc<LedMan
         "You've got the package, now make sure it gets there faster than your last excuse",
     ];
 
-    update_timer.start(
-        slint::TimerMode::Repeated,
-        std::time::Duration::from_millis(300),
+    scheduler.register(
+        "left_right_signal",
+        std::time::Duration::from_millis(600),
         {
             let weak_window = window.as_weak();
-            let model_clone = model.clone();
-            let mut counter = 0;
-            let mut msg_id: usize = 0;
-            let led_manager_clone = led_manager.clone();
-
             move || {
                 if let Some(main_window) = weak_window.upgrade() {
-                    // Update time and date
-                    let time_data_adapter = &main_window.global::<TimeDateAdapter>();
-                    let now = Local::now();
-
-                    time_data_adapter.set_date(slint::format!("{}", now.format("%A %e %B %Y")));
-                    time_data_adapter.set_time(slint::format!("{}", now.format("%I:%M")));
-                    time_data_adapter.set_time_suffix(slint::format!("{}", now.format("%p")));
-
-                    // Update other UI elements from the model
-                    let model = model_clone.read().unwrap();
-
-                    if let Some(ref battery) = model.battery_data {
-                        let battery_status_adapter = &main_window.global::<BatteryGaugeAdapter>();
-                        battery_status_adapter.set_batteryLevel(battery.battery_level.round());
-                        battery_status_adapter.set_isCharging(battery.is_charging);
-                        battery_status_adapter.set_estimatedRange(battery.estimated_range as i32);
-                        battery_status_adapter
-                            .set_timeToFullCharge(battery.time_to_fully_charge as i32);
-                    }
-                    let mut is_lock: bool = false;
-                    if let Some(ref state) = model.lock_state {
-                        is_lock = state.state == State::Lock as i32;
-                        let state_adapter = &main_window.global::<StateAdapter>();
-                        state_adapter.set_isLocked(is_lock);
-                        if !is_lock {
-                            if let Err(e) = led_manager_clone.lock_light() {
-                                error!("Failed to lock light {:?}", e);
-                            }
-                        } else {
-                            if let Err(e) = led_manager_clone.unlock_light() {
-                                error!("Failed to unlock light {:?}", e);
-                            }
-                        }
-                    }
-
-                    if let Some(ref exterior) = model.exterior {
-                        let temperature_adapter =
-                            &main_window.global::<AmbientTemperatureAdapter>();
-                        temperature_adapter.set_temperature(exterior.air_temperature as i32);
-                    }
-
-                    if let Some(ref speed) = model.speed {
-                        let speedometer_adapter = &main_window.global::<SpeedometerAdapter>();
-                        speedometer_adapter.set_speed(speed.value as i32);
-                    }
-
-                    if let Some(ref trip_data) = model.trip_data {
-                        let trip_data_adapter = &main_window.global::<TripDataAdapter>();
-                        trip_data_adapter.set_distance(trip_data.traveled_distance);
-                        trip_data_adapter.set_sinceStart(trip_data.traveled_distance_since_start);
-                        trip_data_adapter.set_averageSpeed(trip_data.average_speed);
-                        trip_data_adapter
-                            .set_time(minutes_to_ddhhmm(trip_data.trip_duration as f32).into());
-                    }
-
                     let tell_tales_adapter_adapter: &TellTalesAdapter<'_> =
                         &main_window.global::<TellTalesAdapter>();
-                    let notifications_adapter: &CourrierNotificationsAdpater<'_> =
-                        &main_window.global::<CourrierNotificationsAdpater>();
-
-                    if counter % 2 == 0 {
-                        let state = tell_tales_adapter_adapter.get_left_signal();
-                        tell_tales_adapter_adapter.set_left_signal(!state);
-                        tell_tales_adapter_adapter.set_right_signal(!state);
-                        if is_lock {
-                            if let Err(e) = led_manager_clone.blinker_led(state) {
-                                error!("Failed to lock light {:?}", e);
-                            }
+                    let state = tell_tales_adapter_adapter.get_left_signal();
+                    tell_tales_adapter_adapter.set_left_signal(!state);
+                    tell_tales_adapter_adapter.set_right_signal(!state);
+
+                    let is_lock = model
+                        .read()
+                        .unwrap()
+                        .lock_state
+                        .as_ref()
+                        .map(|lock_state| lock_state.state == State::Lock as i32)
+                        .unwrap_or(false);
+                    if is_lock {
+                        if let Err(e) = led_manager.blinker_led(state) {
+                            error!("Failed to lock light {:?}", e);
                         }
-                    } else if counter % 5 == 0 {
-                        notifications_adapter.set_message(messages[msg_id].into());
-                        msg_id = if msg_id < messages.len() - 1 {
-                            msg_id + 1
-                        } else {
-                            0
-                        };
-                    } else if counter % 12 == 0 {
-                        tell_tales_adapter_adapter
-                            .set_highbeam(!tell_tales_adapter_adapter.get_highbeam());
-                    } else if counter % 15 == 0 {
-                        tell_tales_adapter_adapter.set_fog(!tell_tales_adapter_adapter.get_fog());
-                    } else if counter % 17 == 0 {
-                        tell_tales_adapter_adapter
-                            .set_bendbeam(!tell_tales_adapter_adapter.get_bendbeam());
-                        tell_tales_adapter_adapter
-                            .set_brake(!tell_tales_adapter_adapter.get_brake());
-                    } else if counter % 11 == 0 {
-                        tell_tales_adapter_adapter.set_park(!tell_tales_adapter_adapter.get_park());
-                    } else if counter % 25 == 0 {
-                        tell_tales_adapter_adapter.set_tire(!tell_tales_adapter_adapter.get_tire());
                     }
-
-                    counter += 1;
                 } else {
                     error!("Failed to update main window!");
                 }
@@ -191,9 +121,166 @@ fn setup(window: &MainWindow, model: Arc<RwLock<Model>>, led_manager: Arc<LedMan
         },
     );
 
+    scheduler.register("notification", std::time::Duration::from_millis(1500), {
+        let weak_window = window.as_weak();
+        let mut msg_id: usize = 0;
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let notifications_adapter: &CourrierNotificationsAdpater<'_> =
+                    &main_window.global::<CourrierNotificationsAdpater>();
+                notifications_adapter.set_message(messages[msg_id].into());
+                msg_id = if msg_id < messages.len() - 1 {
+                    msg_id + 1
+                } else {
+                    0
+                };
+            } else {
+                error!("Failed to update main window!");
+            }
+        }
+    });
+
+    scheduler.register("highbeam", std::time::Duration::from_millis(3600), {
+        let weak_window = window.as_weak();
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let tell_tales_adapter_adapter: &TellTalesAdapter<'_> =
+                    &main_window.global::<TellTalesAdapter>();
+                tell_tales_adapter_adapter.set_highbeam(!tell_tales_adapter_adapter.get_highbeam());
+            } else {
+                error!("Failed to update main window!");
+            }
+        }
+    });
+
+    scheduler.register("fog", std::time::Duration::from_millis(4500), {
+        let weak_window = window.as_weak();
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let tell_tales_adapter_adapter: &TellTalesAdapter<'_> =
+                    &main_window.global::<TellTalesAdapter>();
+                tell_tales_adapter_adapter.set_fog(!tell_tales_adapter_adapter.get_fog());
+            } else {
+                error!("Failed to update main window!");
+            }
+        }
+    });
+
+    scheduler.register("bendbeam_brake", std::time::Duration::from_millis(5100), {
+        let weak_window = window.as_weak();
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let tell_tales_adapter_adapter: &TellTalesAdapter<'_> =
+                    &main_window.global::<TellTalesAdapter>();
+                tell_tales_adapter_adapter.set_bendbeam(!tell_tales_adapter_adapter.get_bendbeam());
+                tell_tales_adapter_adapter.set_brake(!tell_tales_adapter_adapter.get_brake());
+            } else {
+                error!("Failed to update main window!");
+            }
+        }
+    });
+
+    scheduler.register("park", std::time::Duration::from_millis(3300), {
+        let weak_window = window.as_weak();
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let tell_tales_adapter_adapter: &TellTalesAdapter<'_> =
+                    &main_window.global::<TellTalesAdapter>();
+                tell_tales_adapter_adapter.set_park(!tell_tales_adapter_adapter.get_park());
+            } else {
+                error!("Failed to update main window!");
+            }
+        }
+    });
+
+    scheduler.register("tire", std::time::Duration::from_millis(7500), {
+        let weak_window = window.as_weak();
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let tell_tales_adapter_adapter: &TellTalesAdapter<'_> =
+                    &main_window.global::<TellTalesAdapter>();
+                tell_tales_adapter_adapter.set_tire(!tell_tales_adapter_adapter.get_tire());
+            } else {
+                error!("Failed to update main window!");
+            }
+        }
+    });
+}
+
+fn setup(window: &MainWindow, model: Arc<RwLock<Model>>, led_manager: Arc<LedManager>) -> Timer {
+    let update_timer = Timer::default();
+
+    let mut scheduler = AnimationScheduler::new(TICK);
+    register_animations(&mut scheduler, window, model, led_manager);
+
+    // This timer drives the wall clock and advances the animation
+    // scheduler, which fires whichever named animations are due this tick.
+    // Everything fed by Zenoh is pushed to its adapter the moment it
+    // arrives instead of waiting for this tick.
+    update_timer.start(slint::TimerMode::Repeated, TICK, {
+        let weak_window = window.as_weak();
+
+        move || {
+            if let Some(main_window) = weak_window.upgrade() {
+                let time_data_adapter = &main_window.global::<TimeDateAdapter>();
+                let now = Local::now();
+
+                time_data_adapter.set_date(slint::format!("{}", now.format("%A %e %B %Y")));
+                time_data_adapter.set_time(slint::format!("{}", now.format("%I:%M")));
+                time_data_adapter.set_time_suffix(slint::format!("{}", now.format("%p")));
+            } else {
+                error!("Failed to update main window!");
+            }
+
+            scheduler.advance();
+        }
+    });
+
     update_timer
 }
 
+// One topic's decoded message, tagged so all five subscriptions can be
+// merged into a single update loop instead of a channel+task per topic.
+enum TopicUpdate {
+    LockState(LockState),
+    Battery(BatteryData),
+    Exterior(Exterior),
+    Speed(Speed),
+    TripData(TripData),
+}
+
+type UpdateStream = Pin<Box<dyn Stream<Item = TopicUpdate> + Send>>;
+
+// Declares a subscriber and turns it into an `UpdateStream`, tagging each
+// decoded message with `wrap`; decode failures are logged and then skipped.
+// Returns `None` if the subscriber itself couldn't be declared.
+async fn topic_stream<T>(
+    session: Arc<Session>,
+    key_expr: &'static str,
+    wrap: fn(T) -> TopicUpdate,
+) -> Option<UpdateStream>
+where
+    T: Message + Default + Send + Sync + 'static,
+{
+    match ZenohSubscriber::new(session, key_expr).await {
+        Ok(subscriber) => Some(Box::pin(subscriber.into_stream::<T>().filter_map(
+            move |result| async move {
+                match result {
+                    Ok(message) => Some(wrap(message)),
+                    Err(e) => {
+                        error!("Failed to decode message on {}: {:?}", key_expr, e);
+                        None
+                    }
+                }
+            },
+        ))),
+        Err(e) => {
+            error!("Failed to create subscriber for {}: {:?}", key_expr, e);
+            None
+        }
+    }
+}
+
 #[tokio::main(flavor = "multi_thread", worker_threads = 1)]
 async fn main() -> Result<(), slint::PlatformError> {
     // Parse command line arguments
@@ -204,90 +291,221 @@ async fn main() -> Result<(), slint::PlatformError> {
     let main_window = MainWindow::new().unwrap();
 
     // Create the shared model
-    let model = Arc::new(RwLock::new(Model {
-        battery_data: None,
-        lock_state: None,
-        exterior: None,
-        speed: None,
-        trip_data: None,
-    }));
+    let model = Arc::new(RwLock::new(Model { lock_state: None }));
 
     let led_manager = Arc::new(LedManager::default());
 
     // Setup the timer
-    let _timer = setup(&main_window, model.clone(), led_manager.clone());
+    let timer = setup(&main_window, model.clone(), led_manager.clone());
 
     // Create a Zenoh session and wrap it in Arc
     let session = Arc::new(zenoh::open(Config::default()).await.unwrap());
 
-    // Spawn subscriber tasks
-    let (state_tx, mut state_rx) = mpsc::channel::<LockState>(32);
-    SubscriberTaskSpawner::spawn_task(session.clone(), LOCK_STATE_TOPIC, state_tx);
-
-    let (battery_tx, mut battery_rx) = mpsc::channel::<BatteryData>(100);
-    SubscriberTaskSpawner::spawn_task(session.clone(), BATTERY_STATE_TOPIC, battery_tx);
-
-    let (exterior_tx, mut exterior_rx) = mpsc::channel::<Exterior>(100);
-    SubscriberTaskSpawner::spawn_task(session.clone(), EXTERIOR_TOPIC, exterior_tx);
-
-    let (speed_tx, mut speed_rx) = mpsc::channel::<Speed>(100);
-    SubscriberTaskSpawner::spawn_task(session.clone(), SPEED_TOPIC, speed_tx);
-
-    let (trip_data_tx, mut trip_data_rx) = mpsc::channel::<TripData>(100);
-    SubscriberTaskSpawner::spawn_task(session.clone(), TRIP_DATA_TOPIC, trip_data_tx);
-
-    // Spawn tasks to receive data and update the model
-    tokio::spawn({
+    // Cancelling this token tells the merged subscriber loop to stop, as
+    // the first step of a graceful shutdown.
+    let cancel = CancellationToken::new();
+
+    // Declare all five subscriptions and merge them into a single stream
+    // instead of hand-spawning a task and channel per topic.
+    let mut streams: Vec<UpdateStream> = Vec::new();
+    if let Some(s) =
+        topic_stream::<LockState>(session.clone(), LOCK_STATE_TOPIC, TopicUpdate::LockState).await
+    {
+        streams.push(s);
+    }
+    if let Some(s) =
+        topic_stream::<BatteryData>(session.clone(), BATTERY_STATE_TOPIC, TopicUpdate::Battery)
+            .await
+    {
+        streams.push(s);
+    }
+    if let Some(s) =
+        topic_stream::<Exterior>(session.clone(), EXTERIOR_TOPIC, TopicUpdate::Exterior).await
+    {
+        streams.push(s);
+    }
+    if let Some(s) = topic_stream::<Speed>(session.clone(), SPEED_TOPIC, TopicUpdate::Speed).await {
+        streams.push(s);
+    }
+    if let Some(s) =
+        topic_stream::<TripData>(session.clone(), TRIP_DATA_TOPIC, TopicUpdate::TripData).await
+    {
+        streams.push(s);
+    }
+    let mut updates = stream::select_all(streams);
+
+    // Pushes each update straight into the adapter(s) it owns via
+    // `invoke_from_event_loop`, as soon as it arrives, instead of stashing
+    // it in a model for a polling timer to pick up later.
+    let subscriber_task = tokio::spawn({
         let model_clone = model.clone();
+        let weak_window = main_window.as_weak();
+        let led_manager_clone = led_manager.clone();
+        let cancel = cancel.clone();
         async move {
-            while let Some(battery) = battery_rx.recv().await {
-                trace!("Received BatteryData: {:?}", battery);
-                let mut model = model_clone.write().unwrap();
-                model.battery_data = Some(battery);
+            loop {
+                let update = tokio::select! {
+                    _ = cancel.cancelled() => break,
+                    update = updates.next() => match update {
+                        Some(update) => update,
+                        None => break,
+                    },
+                };
+
+                match update {
+                    TopicUpdate::Battery(battery) => {
+                        trace!("Received BatteryData: {:?}", battery);
+                        let weak_window = weak_window.clone();
+                        if let Err(e) = slint::invoke_from_event_loop(move || {
+                            if let Some(main_window) = weak_window.upgrade() {
+                                let battery_status_adapter =
+                                    &main_window.global::<BatteryGaugeAdapter>();
+                                battery_status_adapter
+                                    .set_batteryLevel(battery.battery_level.round());
+                                battery_status_adapter.set_isCharging(battery.is_charging);
+                                battery_status_adapter
+                                    .set_estimatedRange(battery.estimated_range as i32);
+                                battery_status_adapter
+                                    .set_timeToFullCharge(battery.time_to_fully_charge as i32);
+                            } else {
+                                error!("Failed to update main window!");
+                            }
+                        }) {
+                            error!("Failed to schedule battery UI update: {:?}", e);
+                        }
+                    }
+                    TopicUpdate::LockState(state) => {
+                        trace!("Received LockState: {:?}", state);
+                        let is_lock = state.state == State::Lock as i32;
+                        {
+                            let mut model = model_clone.write().unwrap();
+                            model.lock_state = Some(state);
+                        }
+
+                        if !is_lock {
+                            if let Err(e) = led_manager_clone.lock_light() {
+                                error!("Failed to lock light {:?}", e);
+                            }
+                        } else if let Err(e) = led_manager_clone.unlock_light() {
+                            error!("Failed to unlock light {:?}", e);
+                        }
+
+                        let weak_window = weak_window.clone();
+                        if let Err(e) = slint::invoke_from_event_loop(move || {
+                            if let Some(main_window) = weak_window.upgrade() {
+                                let state_adapter = &main_window.global::<StateAdapter>();
+                                state_adapter.set_isLocked(is_lock);
+                            } else {
+                                error!("Failed to update main window!");
+                            }
+                        }) {
+                            error!("Failed to schedule lock state UI update: {:?}", e);
+                        }
+                    }
+                    TopicUpdate::Exterior(exterior) => {
+                        trace!("Received Exterior: {:?}", exterior);
+                        let weak_window = weak_window.clone();
+                        if let Err(e) = slint::invoke_from_event_loop(move || {
+                            if let Some(main_window) = weak_window.upgrade() {
+                                let temperature_adapter =
+                                    &main_window.global::<AmbientTemperatureAdapter>();
+                                temperature_adapter
+                                    .set_temperature(exterior.air_temperature as i32);
+                            } else {
+                                error!("Failed to update main window!");
+                            }
+                        }) {
+                            error!("Failed to schedule exterior UI update: {:?}", e);
+                        }
+                    }
+                    TopicUpdate::Speed(speed) => {
+                        trace!("Received Speed: {:?}", speed);
+                        let weak_window = weak_window.clone();
+                        if let Err(e) = slint::invoke_from_event_loop(move || {
+                            if let Some(main_window) = weak_window.upgrade() {
+                                let speedometer_adapter =
+                                    &main_window.global::<SpeedometerAdapter>();
+                                speedometer_adapter.set_speed(speed.value as i32);
+                            } else {
+                                error!("Failed to update main window!");
+                            }
+                        }) {
+                            error!("Failed to schedule speed UI update: {:?}", e);
+                        }
+                    }
+                    TopicUpdate::TripData(trip_data) => {
+                        trace!("Received TripData: {:?}", trip_data);
+                        let weak_window = weak_window.clone();
+                        if let Err(e) = slint::invoke_from_event_loop(move || {
+                            if let Some(main_window) = weak_window.upgrade() {
+                                let trip_data_adapter = &main_window.global::<TripDataAdapter>();
+                                trip_data_adapter.set_distance(trip_data.traveled_distance);
+                                trip_data_adapter
+                                    .set_sinceStart(trip_data.traveled_distance_since_start);
+                                trip_data_adapter.set_averageSpeed(trip_data.average_speed);
+                                trip_data_adapter.set_time(
+                                    minutes_to_ddhhmm(trip_data.trip_duration as f32).into(),
+                                );
+                            } else {
+                                error!("Failed to update main window!");
+                            }
+                        }) {
+                            error!("Failed to schedule trip data UI update: {:?}", e);
+                        }
+                    }
+                }
             }
         }
     });
 
+    // Wait for Ctrl+C or SIGTERM, then unwind: stop the subscriber loop,
+    // drive the LEDs to a safe state, and finally ask the Slint event loop
+    // to quit.
     tokio::spawn({
-        let model_clone = model.clone();
+        let cancel = cancel.clone();
+        let led_manager = led_manager.clone();
+        let timer = timer.clone();
         async move {
-            while let Some(state) = state_rx.recv().await {
-                trace!("Received LockState: {:?}", state);
-                let mut model = model_clone.write().unwrap();
-                model.lock_state = Some(state);
+            let ctrl_c = tokio::signal::ctrl_c();
+            #[cfg(unix)]
+            {
+                let mut sigterm =
+                    tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+                        .expect("Failed to install SIGTERM handler");
+                tokio::select! {
+                    _ = ctrl_c => {},
+                    _ = sigterm.recv() => {},
+                }
+            }
+            #[cfg(not(unix))]
+            {
+                let _ = ctrl_c.await;
             }
-        }
-    });
 
-    tokio::spawn({
-        let model_clone = model.clone();
-        async move {
-            while let Some(exterior) = exterior_rx.recv().await {
-                trace!("Received Exterior: {:?}", exterior);
-                let mut model = model_clone.write().unwrap();
-                model.exterior = Some(exterior);
+            info!("Shutdown signal received, cleaning up...");
+            cancel.cancel();
+            let _ = subscriber_task.await;
+
+            // Stop the animation scheduler before fading the LEDs: otherwise
+            // the tell-tale timer keeps firing on the main thread during the
+            // fade and `left_right_signal` can retarget the LED channel back
+            // to duty 1.0 right as we're trying to drive it to 0.
+            let (stopped_tx, stopped_rx) = tokio::sync::oneshot::channel();
+            if let Err(e) = slint::invoke_from_event_loop(move || {
+                timer.stop();
+                let _ = stopped_tx.send(());
+            }) {
+                error!("Failed to schedule animation timer stop: {:?}", e);
+            } else {
+                let _ = stopped_rx.await;
             }
-        }
-    });
 
-    tokio::spawn({
-        let model_clone = model.clone();
-        async move {
-            while let Some(speed) = speed_rx.recv().await {
-                trace!("Received Speed: {:?}", speed);
-                let mut model = model_clone.write().unwrap();
-                model.speed = Some(speed);
+            if let Err(e) = led_manager.shutdown().await {
+                error!("Failed to drive LEDs to a safe state: {:?}", e);
             }
-        }
-    });
 
-    tokio::spawn({
-        let model_clone = model.clone();
-        async move {
-            while let Some(trip_data) = trip_data_rx.recv().await {
-                trace!("Received TripData: {:?}", trip_data);
-                let mut model = model_clone.write().unwrap();
-                model.trip_data = Some(trip_data);
+            if let Err(e) = slint::quit_event_loop() {
+                error!("Failed to quit event loop: {:?}", e);
             }
         }
     });
@@ -13,11 +13,13 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use futures::stream::{self, Stream, StreamExt};
 use log::error;
-use prost::Message;
+use prost::{DecodeError, Message};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 use zenoh::handlers::FifoChannelHandler;
 use zenoh::pubsub::Subscriber;
 use zenoh::sample::Sample;
@@ -35,15 +37,40 @@ impl ZenohSubscriber {
         let subscriber = session.declare_subscriber(key_expr).await?;
         Ok(ZenohSubscriber { subscriber })
     }
+
+    /// Turns this subscriber into a `Stream` that decodes every incoming
+    /// sample as `T`, yielding decode failures as `Err` items rather than
+    /// logging and dropping them. Mirrors `tokio_util::io::ReaderStream`:
+    /// one `recv_async().await` per item, decoding folded in.
+    pub fn into_stream<T>(self) -> impl Stream<Item = Result<T, DecodeError>>
+    where
+        T: Message + Default,
+    {
+        stream::unfold(self.subscriber, |subscriber| async move {
+            match subscriber.recv_async().await {
+                Ok(sample) => {
+                    let bytes = sample.payload().to_bytes();
+                    Some((T::decode(&*bytes), subscriber))
+                }
+                Err(_) => None,
+            }
+        })
+    }
 }
 
 pub struct SubscriberTaskSpawner;
 
 impl SubscriberTaskSpawner {
+    /// Thin wrapper over [`ZenohSubscriber::into_stream`] for callers that
+    /// want a channel instead of composing the stream directly. Forwards
+    /// decoded messages into `sender` until `cancel` fires or the stream
+    /// ends, then drops the stream (and with it the `Subscriber`, which
+    /// undeclares itself on drop).
     pub fn spawn_task<T>(
         session: Arc<Session>,
         key_expr: &'static str,
         sender: mpsc::Sender<T>,
+        cancel: CancellationToken,
     ) -> JoinHandle<()>
     where
         T: Message + Default + Send + Sync + 'static,
@@ -51,18 +78,25 @@ impl SubscriberTaskSpawner {
         tokio::spawn(async move {
             match ZenohSubscriber::new(session, key_expr).await {
                 Ok(subscriber) => {
-                    while let Ok(sample) = subscriber.subscriber.recv_async().await {
-                        let bytes = sample.payload().to_bytes();
-                        match T::decode(&*bytes) {
-                            Ok(message) => {
-                                if let Err(err) = sender.send(message).await {
-                                    error!("Failed to send message through channel: {:?}", err);
-                                    break;
+                    let stream = subscriber.into_stream::<T>();
+                    futures::pin_mut!(stream);
+                    loop {
+                        tokio::select! {
+                            _ = cancel.cancelled() => break,
+                            item = stream.next() => {
+                                match item {
+                                    Some(Ok(message)) => {
+                                        if let Err(err) = sender.send(message).await {
+                                            error!("Failed to send message through channel: {:?}", err);
+                                            break;
+                                        }
+                                    }
+                                    Some(Err(e)) => {
+                                        error!("Failed to decode message: {:?}", e);
+                                    }
+                                    None => break,
                                 }
                             }
-                            Err(e) => {
-                                error!("Failed to decode message: {:?}", e);
-                            }
                         }
                     }
                 }
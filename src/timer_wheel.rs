@@ -0,0 +1,116 @@
+// Copyright (C) 2024 OpenTier FZCO
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use log::trace;
+use std::time::Duration;
+
+// Number of slots in the wheel. Any period that doesn't fit in a single
+// revolution (period_ticks > WHEEL_SIZE) just carries a round count and
+// waits out the extra revolutions, so this only needs to be big enough to
+// keep the round counts small for our longest animation period.
+const WHEEL_SIZE: usize = 64;
+
+/// Number of full extra revolutions a period of `period_ticks` needs to
+/// wait out before its slot is due. `period_ticks / WHEEL_SIZE` overcounts
+/// by one whole revolution whenever `period_ticks` is an exact multiple of
+/// `WHEEL_SIZE`, since the slot offset is then 0 and no extra round is
+/// needed.
+fn rounds_for(period_ticks: usize) -> usize {
+    (period_ticks - 1) / WHEEL_SIZE
+}
+
+struct Entry {
+    name: &'static str,
+    period_ticks: usize,
+    rounds_remaining: usize,
+    callback: Box<dyn FnMut() + Send>,
+}
+
+/// A hashed timing wheel (as used by `mio-extras`) for driving several
+/// independent, concurrently-running periodic animations off a single
+/// tick, instead of an `else if counter % N` ladder where only one branch
+/// can fire per tick.
+pub struct AnimationScheduler {
+    tick: Duration,
+    wheel: Vec<Vec<Entry>>,
+    cursor: usize,
+}
+
+impl AnimationScheduler {
+    pub fn new(tick: Duration) -> Self {
+        Self {
+            tick,
+            wheel: (0..WHEEL_SIZE).map(|_| Vec::new()).collect(),
+            cursor: 0,
+        }
+    }
+
+    /// Registers a named animation that fires every `period`, starting one
+    /// `period` from now. `period` is rounded down to the nearest whole
+    /// tick, with a minimum of one tick.
+    pub fn register(
+        &mut self,
+        name: &'static str,
+        period: Duration,
+        callback: impl FnMut() + Send + 'static,
+    ) {
+        let period_ticks = ((period.as_millis() / self.tick.as_millis()).max(1)) as usize;
+        self.schedule(Entry {
+            name,
+            period_ticks,
+            rounds_remaining: rounds_for(period_ticks),
+            callback: Box::new(callback),
+        });
+    }
+
+    fn schedule(&mut self, entry: Entry) {
+        let slot = (self.cursor + entry.period_ticks) % WHEEL_SIZE;
+        self.wheel[slot].push(entry);
+    }
+
+    /// Advances the wheel by one tick, firing and rescheduling every entry
+    /// that is due in the newly-current slot.
+    pub fn advance(&mut self) {
+        self.cursor = (self.cursor + 1) % WHEEL_SIZE;
+
+        let due: Vec<Entry> = {
+            let slot = &mut self.wheel[self.cursor];
+            let mut due = Vec::new();
+            let mut still_waiting = Vec::with_capacity(slot.len());
+            for mut entry in slot.drain(..) {
+                if entry.rounds_remaining > 0 {
+                    entry.rounds_remaining -= 1;
+                    still_waiting.push(entry);
+                } else {
+                    due.push(entry);
+                }
+            }
+            *slot = still_waiting;
+            due
+        };
+
+        for mut entry in due {
+            trace!("Firing animation {:?}", entry.name);
+            (entry.callback)();
+            let rounds_remaining = rounds_for(entry.period_ticks);
+            self.schedule(Entry {
+                name: entry.name,
+                period_ticks: entry.period_ticks,
+                rounds_remaining,
+                callback: entry.callback,
+            });
+        }
+    }
+}